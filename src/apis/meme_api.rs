@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+const MEME_API_URL: &str = "https://meme-api.com/gimme";
+
+/// A single meme returned by the meme API.
+#[derive(Debug, Deserialize)]
+pub struct Meme {
+    pub url: String,
+}
+
+/// Fetches a random meme.
+pub async fn get() -> Result<Meme, reqwest::Error> {
+    reqwest::get(MEME_API_URL).await?.json::<Meme>().await
+}
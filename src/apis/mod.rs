@@ -0,0 +1,2 @@
+pub mod meme_api;
+pub mod uselessfact;
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+const TODAY_URL: &str = "https://uselessfacts.jsph.pl/api/v2/facts/today";
+const RANDOM_URL: &str = "https://uselessfacts.jsph.pl/api/v2/facts/random";
+
+/// A single useless fact.
+#[derive(Debug, Deserialize)]
+pub struct Fact {
+    pub text: String,
+}
+
+/// Fetches the fact of the day in the given language (defaults to "en").
+pub async fn daily(language: Option<String>) -> Result<Fact, reqwest::Error> {
+    fetch(TODAY_URL, language).await
+}
+
+/// Fetches a random fact in the given language (defaults to "en").
+pub async fn random(language: Option<String>) -> Result<Fact, reqwest::Error> {
+    fetch(RANDOM_URL, language).await
+}
+
+async fn fetch(url: &str, language: Option<String>) -> Result<Fact, reqwest::Error> {
+    let language = language.unwrap_or_else(|| "en".to_string());
+    reqwest::get(format!("{}?language={}", url, language))
+        .await?
+        .json::<Fact>()
+        .await
+}
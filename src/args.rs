@@ -0,0 +1,132 @@
+use clap::{Parser, Subcommand, ValueEnum};
+
+/// Command-line arguments accepted by `mensa-cli`.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// The top-level action `mensa-cli` should perform.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Fetch and print meals for one or more canteens.
+    Meals(MealsArgs),
+    /// Fetch and print a random meme.
+    Meme,
+    /// Fetch a useless fact.
+    Fact {
+        #[command(subcommand)]
+        kind: FactKind,
+    },
+    /// Start the Discord bot.
+    Bot(BotArgs),
+    /// Manage the on-disk response cache.
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+}
+
+/// Arguments specific to the `meals` subcommand.
+#[derive(clap::Args, Debug)]
+pub struct MealsArgs {
+    /// Canteen ID to fetch meals for.
+    #[arg(long, conflicts_with = "location")]
+    pub id: Option<u32>,
+
+    /// Location string to search canteens by.
+    #[arg(long)]
+    pub location: Option<String>,
+
+    /// Date(s) to fetch meals for: a single date (`YYYY-MM-DD`, "today",
+    /// "tomorrow"), a range (`2024-01-08..2024-01-12`), "week" for the
+    /// current Monday-Friday span, or a comma-separated list of any of those.
+    #[arg(long, default_value = "today")]
+    pub date: String,
+
+    /// Bypass the response cache and always fetch fresh data.
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Output format used when printing meals.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Export meals as an iCalendar feed instead of printing them. Pass a
+    /// path to write to a file, or omit the value to print to stdout.
+    #[arg(long, num_args = 0..=1, default_missing_value = "-")]
+    pub ical: Option<String>,
+
+    /// WebDAV/CalDAV endpoint to PUT the generated `.ics` to (e.g. a Nextcloud calendar URL).
+    #[arg(long)]
+    pub webdav_url: Option<String>,
+
+    /// WebDAV username. Falls back to the `WEBDAV_USER` variable in `.env`.
+    #[arg(long)]
+    pub webdav_user: Option<String>,
+
+    /// WebDAV password. Falls back to the `WEBDAV_PASSWORD` variable in `.env`.
+    #[arg(long)]
+    pub webdav_password: Option<String>,
+
+    /// Path to a .env file containing the `WEBDAV_USER`/`WEBDAV_PASSWORD` variables.
+    #[arg(long)]
+    pub env_file: Option<String>,
+
+    /// Only keep vegetarian meals (vegan meals count as vegetarian too).
+    #[arg(long)]
+    pub vegetarian: bool,
+
+    /// Only keep vegan meals.
+    #[arg(long)]
+    pub vegan: bool,
+
+    /// Only keep meals in the given category.
+    #[arg(long)]
+    pub category: Option<String>,
+
+    /// Hide meals priced above this amount (student price, in euro).
+    #[arg(long)]
+    pub max_price: Option<f64>,
+}
+
+/// Which useless fact to fetch.
+#[derive(Subcommand, Debug)]
+pub enum FactKind {
+    /// The fact of the day.
+    Daily,
+    /// A random fact.
+    Random,
+}
+
+/// Arguments specific to the `bot` subcommand.
+#[derive(clap::Args, Debug)]
+pub struct BotArgs {
+    /// Discord bot token.
+    #[arg(long)]
+    pub token: Option<String>,
+
+    /// Path to a .env file containing the Discord bot token.
+    #[arg(long)]
+    pub env_file: Option<String>,
+}
+
+/// Cache maintenance actions.
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// Wipe the on-disk response cache.
+    Clear,
+}
+
+/// Output format for printed meals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable grid, rendered with `tabled`.
+    Table,
+    /// One JSON array of meals per canteen per date.
+    Json,
+    /// Comma-separated values, one meal per row.
+    Csv,
+}
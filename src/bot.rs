@@ -0,0 +1,34 @@
+use serenity::async_trait;
+use serenity::model::channel::Message;
+use serenity::model::gateway::Ready;
+use serenity::prelude::*;
+
+struct Handler;
+
+#[async_trait]
+impl EventHandler for Handler {
+    async fn message(&self, ctx: Context, msg: Message) {
+        if msg.content == "!meals" {
+            if let Err(err) = msg.channel_id.say(&ctx.http, "Fetching meals is not wired up here yet.").await {
+                eprintln!("Error sending message: {:?}", err);
+            }
+        }
+    }
+
+    async fn ready(&self, _: Context, ready: Ready) {
+        println!("{} is connected!", ready.user.name);
+    }
+}
+
+/// Starts the Discord bot and blocks until it disconnects.
+pub async fn start_bot(token: &str) {
+    let intents = GatewayIntents::GUILD_MESSAGES | GatewayIntents::MESSAGE_CONTENT;
+    let mut client = Client::builder(token, intents)
+        .event_handler(Handler)
+        .await
+        .expect("Error creating Discord client");
+
+    if let Err(err) = client.start().await {
+        eprintln!("Client error: {:?}", err);
+    }
+}
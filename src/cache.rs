@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fs;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// TTL applied to canteen metadata lookups, which rarely change.
+pub const CANTEEN_TTL: Duration = Duration::from_secs(60 * 60 * 24 * 3);
+
+/// TTL applied to meal listings, which can change throughout the day.
+pub const MEAL_TTL: Duration = Duration::from_secs(60 * 60 * 6);
+
+/// A value that has either never been fetched or was fetched at some point in
+/// the past. Read from and written to disk under [`cache_dir`], keyed by the
+/// request that produced it.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Fetchable<T> {
+    None,
+    Fetched { value: T, fetched_at: DateTime<Utc> },
+}
+
+impl<T> Fetchable<T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    /// Loads the cached entry for `key`, or `Fetchable::None` if nothing was
+    /// cached yet or the cache file could not be read.
+    pub fn load(key: &str) -> Self {
+        fs::read_to_string(entry_path(key))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or(Fetchable::None)
+    }
+
+    /// Returns the cached value if it is younger than `ttl`, otherwise runs
+    /// `closure`, persists its result under `key` and returns it.
+    pub async fn fetch<F, Fut, E>(&mut self, key: &str, ttl: Duration, closure: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if let Fetchable::Fetched { value, fetched_at } = self {
+            let age = Utc::now().signed_duration_since(*fetched_at);
+            if age.to_std().map_or(false, |age| age < ttl) {
+                return Ok(value.clone());
+            }
+        }
+
+        let value = closure().await?;
+        *self = Fetchable::Fetched {
+            value: value.clone(),
+            fetched_at: Utc::now(),
+        };
+        self.persist(key);
+        Ok(value)
+    }
+
+    fn persist(&self, key: &str) {
+        let path = entry_path(key);
+        if let Some(parent) = path.parent() {
+            if fs::create_dir_all(parent).is_err() {
+                return;
+            }
+        }
+        if let Ok(json) = serde_json::to_string(self) {
+            let _ = fs::write(path, json);
+        }
+    }
+}
+
+/// Directory holding all cached responses, `~/.cache/mensa-cli/`.
+pub fn cache_dir() -> PathBuf {
+    PathBuf::from(shellexpand::tilde("~/.cache/mensa-cli").into_owned())
+}
+
+fn entry_path(key: &str) -> PathBuf {
+    let file_name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    cache_dir().join(format!("{}.json", file_name))
+}
+
+/// Removes every cached entry, used by the `cache clear` subcommand.
+pub fn clear() -> std::io::Result<()> {
+    let dir = cache_dir();
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fetchable_none_round_trips_through_json() {
+        let entry: Fetchable<String> = Fetchable::None;
+        let json = serde_json::to_string(&entry).unwrap();
+        let restored: Fetchable<String> = serde_json::from_str(&json).unwrap();
+
+        assert!(matches!(restored, Fetchable::None));
+    }
+
+    #[test]
+    fn fetchable_fetched_round_trips_with_value_and_timestamp_intact() {
+        let fetched_at = Utc::now();
+        let entry = Fetchable::Fetched {
+            value: "cached payload".to_string(),
+            fetched_at,
+        };
+
+        let json = serde_json::to_string(&entry).unwrap();
+        let restored: Fetchable<String> = serde_json::from_str(&json).unwrap();
+
+        match restored {
+            Fetchable::Fetched { value, fetched_at: restored_at } => {
+                assert_eq!(value, "cached payload");
+                assert_eq!(restored_at, fetched_at);
+            }
+            Fetchable::None => panic!("expected a Fetched entry"),
+        }
+    }
+}
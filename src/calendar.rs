@@ -0,0 +1,85 @@
+use crate::models::TabledMeal;
+use chrono::{NaiveDate, NaiveTime};
+use icalendar::{Calendar, Component, Event, EventLike};
+
+/// Start of the lunch window used for generated events, when none is configured.
+pub const DEFAULT_LUNCH_START: NaiveTime = NaiveTime::from_hms_opt(11, 30, 0).unwrap();
+
+/// End of the lunch window used for generated events, when none is configured.
+pub const DEFAULT_LUNCH_END: NaiveTime = NaiveTime::from_hms_opt(14, 0, 0).unwrap();
+
+/// A canteen's meals on a single date, ready to become a calendar event.
+pub struct CalendarEntry {
+    pub canteen: String,
+    pub date: NaiveDate,
+    pub meals: Vec<TabledMeal>,
+}
+
+/// WebDAV/CalDAV endpoint and credentials the generated `.ics` is PUT to.
+pub struct WebdavTarget {
+    pub url: String,
+    pub user: String,
+    pub password: String,
+}
+
+/// Builds an iCalendar document with one VEVENT per canteen per date, spanning
+/// the given lunch window and describing the day's meals.
+pub fn build_calendar(entries: &[CalendarEntry], lunch_start: NaiveTime, lunch_end: NaiveTime) -> Calendar {
+    let mut calendar = Calendar::new();
+
+    for entry in entries {
+        let description = entry
+            .meals
+            .iter()
+            .map(|meal| {
+                format!(
+                    "{}: {} ({} / {})",
+                    meal.category, meal.name, meal.price_student, meal.price_employee
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let event = Event::new()
+            .summary(&entry.canteen)
+            .description(&description)
+            .starts(entry.date.and_time(lunch_start))
+            .ends(entry.date.and_time(lunch_end))
+            .done();
+
+        calendar.push(event);
+    }
+
+    calendar.done()
+}
+
+/// Parses an `HH:MM` config value into a `NaiveTime`, falling back to
+/// `default` when the value is absent or malformed.
+pub fn parse_lunch_time(value: Option<&str>, default: NaiveTime) -> NaiveTime {
+    value
+        .and_then(|value| NaiveTime::parse_from_str(value, "%H:%M").ok())
+        .unwrap_or(default)
+}
+
+/// PUTs the rendered `.ics` document to a CalDAV/WebDAV endpoint using basic auth.
+pub async fn upload(target: &WebdavTarget, ics: &str) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let response = client
+        .put(&target.url)
+        .basic_auth(&target.user, Some(&target.password))
+        .header("Content-Type", "text/calendar; charset=utf-8")
+        .body(ics.to_string())
+        .send()
+        .await
+        .map_err(|err| format!("Error uploading calendar to {}: {}", target.url, err))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "WebDAV upload to {} failed with status {}",
+            target.url,
+            response.status()
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,324 @@
+use crate::apis::{meme_api, uselessfact};
+use crate::args::OutputFormat;
+use crate::cache::{Fetchable, MEAL_TTL};
+use crate::calendar::{self, CalendarEntry, WebdavTarget};
+use crate::models::{MealFilter, TabledMeal};
+use chrono::{NaiveDate, NaiveTime};
+use openmensa_rust_interface::Canteen;
+use serde::Serialize;
+use tabled::{
+    settings::{object::Columns, Modify, Style, Width},
+    Table,
+};
+
+/// The meals fetched for a single canteen on a single date, ready to be formatted.
+struct CanteenMeals {
+    canteen: String,
+    date: NaiveDate,
+    meals: Vec<TabledMeal>,
+}
+
+/// Renders a batch of per-canteen, per-date meal listings into a displayable `String`.
+trait MealFormatter {
+    fn format(&self, canteens: &[CanteenMeals]) -> String;
+}
+
+struct TableFormatter;
+
+impl MealFormatter for TableFormatter {
+    fn format(&self, canteens: &[CanteenMeals]) -> String {
+        let mut out = String::new();
+        for entry in canteens {
+            out.push_str(&format!("{} — {}\n", entry.canteen, entry.date));
+            out.push_str(&render_table(&entry.meals));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+struct JsonFormatter;
+
+/// A `TabledMeal` shaped for JSON output, leaving out the internal dietary
+/// `flags` bookkeeping that `TabledMeal` itself keeps around (and
+/// serializes) so it survives a round trip through the cache, and using the
+/// raw numeric prices so consumers don't have to strip a currency symbol.
+#[derive(Serialize)]
+struct JsonMeal<'a> {
+    category: &'a str,
+    meal: &'a str,
+    diet: &'a str,
+    price_student: Option<f64>,
+    price_employee: Option<f64>,
+}
+
+impl<'a> From<&'a TabledMeal> for JsonMeal<'a> {
+    fn from(meal: &'a TabledMeal) -> Self {
+        JsonMeal {
+            category: &meal.category,
+            meal: &meal.name,
+            diet: &meal.icons,
+            price_student: meal.price_student_value,
+            price_employee: meal.price_employee_value,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonCanteenMeals<'a> {
+    canteen: &'a str,
+    date: NaiveDate,
+    meals: Vec<JsonMeal<'a>>,
+}
+
+impl MealFormatter for JsonFormatter {
+    fn format(&self, canteens: &[CanteenMeals]) -> String {
+        let entries: Vec<JsonCanteenMeals> = canteens
+            .iter()
+            .map(|entry| JsonCanteenMeals {
+                canteen: &entry.canteen,
+                date: entry.date,
+                meals: entry.meals.iter().map(JsonMeal::from).collect(),
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&entries)
+            .unwrap_or_else(|err| format!("Error serializing meals to JSON: {}", err))
+    }
+}
+
+struct CsvFormatter;
+
+impl MealFormatter for CsvFormatter {
+    fn format(&self, canteens: &[CanteenMeals]) -> String {
+        let mut out =
+            String::from("canteen,date,category,meal,diet,price_student,price_employee\n");
+        for entry in canteens {
+            for meal in &entry.meals {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{},{}\n",
+                    csv_escape(&entry.canteen),
+                    entry.date,
+                    csv_escape(&meal.category),
+                    csv_escape(&meal.name),
+                    csv_escape(&meal.icons),
+                    csv_price(meal.price_student_value),
+                    csv_price(meal.price_employee_value),
+                ));
+            }
+        }
+        out
+    }
+}
+
+/// Renders a price for CSV output: the raw number with no currency symbol,
+/// or an empty field when the price is unknown.
+fn csv_price(price: Option<f64>) -> String {
+    price.map_or_else(String::new, |price| format!("{:.2}", price))
+}
+
+/// Quotes a CSV field if it contains a comma, quote, or newline.
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn formatter_for(format: OutputFormat) -> Box<dyn MealFormatter> {
+    match format {
+        OutputFormat::Table => Box::new(TableFormatter),
+        OutputFormat::Json => Box::new(JsonFormatter),
+        OutputFormat::Csv => Box::new(CsvFormatter),
+    }
+}
+
+/// Prints meals for multiple canteens across one or more dates.
+///
+/// # Arguments
+///
+/// * `canteens` - A vector of `Canteen` structs for which meals are to be fetched and printed.
+/// * `dates` - The dates for which meals are to be fetched.
+/// * `no_cache` - When `true`, bypasses the response cache and always fetches fresh data.
+/// * `format` - The `OutputFormat` used to render the fetched meals.
+/// * `filter` - Dietary/category/price filter applied before rendering.
+///
+/// # Returns
+///
+/// `Ok(())` if meals are printed successfully, otherwise returns an error message as a `String`.
+pub async fn print_meals(
+    canteens: Vec<Canteen>,
+    dates: Vec<NaiveDate>,
+    no_cache: bool,
+    format: OutputFormat,
+    filter: &MealFilter,
+) -> Result<(), String> {
+    let mut fetched = Vec::with_capacity(canteens.len() * dates.len());
+    for canteen in canteens {
+        for date in &dates {
+            match get_meals_for_canteen(&canteen, date, no_cache).await {
+                Ok(mut meals) => {
+                    meals.retain(|meal| filter.matches(meal));
+                    fetched.push(CanteenMeals {
+                        canteen: canteen.name.clone(),
+                        date: *date,
+                        meals,
+                    })
+                }
+                Err(err) => {
+                    return Err(format!(
+                        "Error fetching meals for {}: {}",
+                        canteen.name, err
+                    ));
+                }
+            }
+        }
+    }
+
+    print!("{}", formatter_for(format).format(&fetched));
+    Ok(())
+}
+
+/// Fetches meals for the given canteens and exports them as an iCalendar feed.
+///
+/// # Arguments
+///
+/// * `canteens` - A vector of `Canteen` structs to build calendar events for.
+/// * `dates` - The dates for which meals are to be fetched.
+/// * `no_cache` - When `true`, bypasses the response cache and always fetches fresh data.
+/// * `target` - Where to write the `.ics` document: `"-"` for stdout, otherwise a file path.
+/// * `filter` - Dietary/category/price filter applied before building events.
+/// * `webdav` - An optional WebDAV/CalDAV endpoint to also PUT the feed to.
+/// * `lunch_start` - Start of the lunch window spanned by each generated event.
+/// * `lunch_end` - End of the lunch window spanned by each generated event.
+///
+/// # Returns
+///
+/// `Ok(())` once the feed was written (and uploaded, if requested), otherwise an error message.
+pub async fn export_ical(
+    canteens: Vec<Canteen>,
+    dates: Vec<NaiveDate>,
+    no_cache: bool,
+    target: &str,
+    filter: &MealFilter,
+    webdav: Option<WebdavTarget>,
+    lunch_start: NaiveTime,
+    lunch_end: NaiveTime,
+) -> Result<(), String> {
+    let mut entries = Vec::with_capacity(canteens.len() * dates.len());
+    for canteen in canteens {
+        for date in &dates {
+            let mut meals = get_meals_for_canteen(&canteen, date, no_cache)
+                .await
+                .map_err(|err| format!("Error fetching meals for {}: {}", canteen.name, err))?;
+            meals.retain(|meal| filter.matches(meal));
+            entries.push(CalendarEntry {
+                canteen: canteen.name.clone(),
+                date: *date,
+                meals,
+            });
+        }
+    }
+
+    let ics = calendar::build_calendar(&entries, lunch_start, lunch_end).to_string();
+
+    if target == "-" {
+        println!("{}", ics);
+    } else {
+        std::fs::write(target, &ics)
+            .map_err(|err| format!("Error writing calendar to {}: {}", target, err))?;
+    }
+
+    if let Some(webdav) = webdav {
+        calendar::upload(&webdav, &ics).await?;
+    }
+
+    Ok(())
+}
+
+/// Retrieves meals for a specific canteen on a given date.
+///
+/// # Arguments
+///
+/// * `canteen` - A reference to the `Canteen` struct for which meals are to be fetched.
+/// * `date` - A reference to the `NaiveDate` for which meals are to be fetched.
+/// * `no_cache` - When `true`, bypasses the response cache and always fetches fresh data.
+///
+/// # Returns
+///
+/// A vector of `TabledMeal` structs representing the meals formatted for tabular display,
+/// or returns an error message as a `String` if fetching fails.
+async fn get_meals_for_canteen(
+    canteen: &Canteen,
+    date: &NaiveDate,
+    no_cache: bool,
+) -> Result<Vec<TabledMeal>, String> {
+    let key = format!("meals/{}/{}", canteen.id, date);
+    let date_str = date.to_string();
+
+    let fetch_meals = || async move {
+        openmensa_rust_interface::get_meals(canteen, &date_str)
+            .await
+            .map(|meals| meals.into_iter().map(TabledMeal::from).collect::<Vec<_>>())
+            .map_err(|err| err.to_string())
+    };
+
+    if no_cache {
+        return fetch_meals().await;
+    }
+
+    let mut entry: Fetchable<Vec<TabledMeal>> = Fetchable::load(&key);
+    entry.fetch(&key, MEAL_TTL, fetch_meals).await
+}
+
+/// Renders a table of `TabledMeal` structs.
+///
+/// # Arguments
+///
+/// * `tabled_meals` - A slice of `TabledMeal` structs to be rendered as a table.
+fn render_table(tabled_meals: &[TabledMeal]) -> String {
+    let mut table = Table::new(tabled_meals);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Columns::first()).with(Width::wrap(10).keep_words()))
+        .with(Modify::new(Columns::last()).with(Width::wrap(10).keep_words()));
+
+    table.to_string()
+}
+
+/// Fetches and prints a meme.
+pub async fn meme() {
+    match meme_api::get().await {
+        Ok(meme) => {
+            println!("{}", meme.url);
+        }
+        Err(err) => {
+            eprintln!("Error fetching meme: {:?}", err);
+        }
+    }
+}
+
+/// Fetches and prints a daily useless fact.
+pub async fn daily_fact() {
+    match uselessfact::daily(Some(String::from("de"))).await {
+        Ok(fact) => {
+            println!("{}", fact.text);
+        }
+        Err(err) => {
+            eprintln!("Error fetching daily fact: {:?}", err);
+        }
+    }
+}
+
+/// Fetches and prints a random useless fact.
+pub async fn random_fact() {
+    match uselessfact::random(Some(String::from("de"))).await {
+        Ok(fact) => {
+            println!("{}", fact.text)
+        }
+        Err(err) => {
+            eprintln!("Error fetching random fact: {:?}", err);
+        }
+    }
+}
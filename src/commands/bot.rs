@@ -0,0 +1,52 @@
+use crate::args::BotArgs;
+use crate::bot;
+use dotenv::dotenv;
+use std::path::Path;
+
+/// Runs the `bot` subcommand: reads the Discord token and starts the bot.
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the bot starts successfully, otherwise returns `Err(String)`.
+pub async fn run(args: &BotArgs) -> Result<(), String> {
+    let token = get_bot_token(args)?;
+    bot::start_bot(&token).await;
+    Ok(())
+}
+
+/// Reads and returns the bot token based on the provided arguments.
+///
+/// # Returns
+///
+/// Returns `Ok(String)` if a token is found, otherwise returns `Err(String)`.
+fn get_bot_token(args: &BotArgs) -> Result<String, String> {
+    if args.token.is_none() && args.env_file.is_none() {
+        let path = Path::new(".env");
+        if !path.exists() {
+            return Err(
+                "Please provide a Discord Token either as a parameter or in a .env file".into(),
+            );
+        }
+
+        dotenv().ok();
+        return std::env::var("DISCORD_TOKEN")
+            .map_err(|_| "Could not find \"DISCORD_TOKEN\" in .env file".into());
+    }
+
+    if let Some(ref env_path) = args.env_file {
+        let path = Path::new(env_path);
+        if !path.exists() {
+            return Err("Wrong path passed to arg".into());
+        }
+
+        dotenv::from_path(path).ok();
+        return std::env::var("DISCORD_TOKEN")
+            .map_err(|_| "Could not find \"DISCORD_TOKEN\" in .env file".into());
+    }
+
+    if let Some(ref arg_token) = args.token {
+        return Ok(arg_token.clone());
+    }
+
+    Err("No valid token source provided".into())
+}
@@ -0,0 +1,12 @@
+use crate::args::CacheAction;
+use crate::cache;
+
+/// Runs the `cache` subcommand.
+pub fn run(action: &CacheAction) {
+    match action {
+        CacheAction::Clear => match cache::clear() {
+            Ok(()) => println!("Cache cleared."),
+            Err(err) => eprintln!("Error clearing cache: {}", err),
+        },
+    }
+}
@@ -0,0 +1,10 @@
+use crate::args::FactKind;
+use crate::cli;
+
+/// Runs the `fact` subcommand.
+pub async fn run(kind: &FactKind) {
+    match kind {
+        FactKind::Daily => cli::daily_fact().await,
+        FactKind::Random => cli::random_fact().await,
+    }
+}
@@ -0,0 +1,337 @@
+use crate::args::MealsArgs;
+use crate::cache::{self, Fetchable};
+use crate::calendar::{self, WebdavTarget};
+use crate::cli;
+use crate::config::Configs;
+use crate::models::MealFilter;
+use chrono::{Datelike, NaiveDate, Utc};
+use dotenv::dotenv;
+use openmensa_rust_interface::{
+    get_canteen_by_id, get_canteens_by_ids, get_canteens_by_location, Canteen,
+};
+use std::path::Path;
+
+/// Runs the `meals` subcommand: fetches the requested canteens and either
+/// prints their meals or exports them as an iCalendar feed.
+///
+/// # Arguments
+///
+/// * `args` - The parsed `meals` subcommand arguments.
+/// * `configs` - Parsed contents of `config.toml`.
+///
+/// # Returns
+///
+/// `Ok(())` on success, otherwise an error message as a `String`.
+pub async fn run(args: &MealsArgs, configs: &Configs) -> Result<(), String> {
+    let Some(canteens) = fetch_canteens(args, configs).await else {
+        return Ok(());
+    };
+
+    let dates = parse_dates(&args.date)?;
+    let filter = resolve_filter(args, configs);
+
+    if let Some(ref ical_target) = args.ical {
+        let webdav = get_webdav_target(args)?;
+        let lunch_start = calendar::parse_lunch_time(
+            configs.calendar.lunch_start.as_deref(),
+            calendar::DEFAULT_LUNCH_START,
+        );
+        let lunch_end = calendar::parse_lunch_time(
+            configs.calendar.lunch_end.as_deref(),
+            calendar::DEFAULT_LUNCH_END,
+        );
+        return cli::export_ical(
+            canteens,
+            dates,
+            args.no_cache,
+            ical_target,
+            &filter,
+            webdav,
+            lunch_start,
+            lunch_end,
+        )
+        .await;
+    }
+
+    cli::print_meals(canteens, dates, args.no_cache, args.format, &filter).await
+}
+
+/// Merges `--vegetarian`/`--vegan`/`--category`/`--max-price` with the
+/// defaults persisted in `config.toml`, with the CLI flags taking priority.
+fn resolve_filter(args: &MealsArgs, configs: &Configs) -> MealFilter {
+    MealFilter {
+        vegetarian: args.vegetarian || configs.filters.vegetarian,
+        vegan: args.vegan || configs.filters.vegan,
+        category: args.category.clone().or_else(|| configs.filters.category.clone()),
+        max_price: args.max_price.or(configs.filters.max_price),
+    }
+}
+
+/// Fetches canteens based on the provided arguments and configurations.
+async fn fetch_canteens(args: &MealsArgs, configs: &Configs) -> Option<Vec<Canteen>> {
+    if let Some(id) = args.id {
+        let key = format!("canteen/{}", id);
+        return match fetch_cached(args.no_cache, &key, cache::CANTEEN_TTL, || async move {
+            get_canteen_by_id(id)
+                .await
+                .map_err(|err| format!("Error fetching canteens by ID: {}", err))
+        })
+        .await
+        {
+            Ok(Some(canteen)) => Some(vec![canteen]), // Wrap the Canteen in a Vec
+            Ok(None) => {
+                eprintln!("Canteen not found by ID");
+                None
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                None
+            }
+        };
+    }
+
+    if let Some(location_str) = args.location.as_deref() {
+        let key = format!("canteens/location/{}", location_str);
+        let location = location_str.to_string();
+        return match fetch_cached(args.no_cache, &key, cache::CANTEEN_TTL, || async move {
+            get_canteens_by_location(&location)
+                .await
+                .map_err(|err| format!("Error fetching canteens by location: {}", err))
+        })
+        .await
+        {
+            Ok(canteens) => Some(canteens),
+            Err(err) => {
+                eprintln!("{}", err);
+                None
+            }
+        };
+    }
+
+    let ids = configs.locations.canteens.to_vec();
+    let key = format!("canteens/ids/{:?}", ids);
+    match fetch_cached(args.no_cache, &key, cache::CANTEEN_TTL, || async move {
+        get_canteens_by_ids(ids)
+            .await
+            .map_err(|err| format!("Error fetching canteens by IDs: {}", err))
+    })
+    .await
+    {
+        Ok(canteens) => Some(canteens),
+        Err(err) => {
+            eprintln!("{}", err);
+            None
+        }
+    }
+}
+
+/// Returns the cached value for `key` if still fresh, otherwise runs `closure`
+/// and memoizes its result. Bypassed entirely when `no_cache` is set.
+async fn fetch_cached<T, F, Fut>(
+    no_cache: bool,
+    key: &str,
+    ttl: std::time::Duration,
+    closure: F,
+) -> Result<T, String>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Clone,
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<T, String>>,
+{
+    if no_cache {
+        return closure().await;
+    }
+
+    let mut entry = Fetchable::load(key);
+    entry.fetch(key, ttl, closure).await
+}
+
+/// Reads the WebDAV endpoint and credentials used to publish the generated
+/// `.ics` feed, if `--webdav-url` was given.
+///
+/// # Returns
+///
+/// Returns `Ok(None)` when no `--webdav-url` was passed, `Ok(Some(WebdavTarget))`
+/// once an URL and credentials were found, otherwise `Err(String)`.
+fn get_webdav_target(args: &MealsArgs) -> Result<Option<WebdavTarget>, String> {
+    let Some(url) = args.webdav_url.clone() else {
+        return Ok(None);
+    };
+
+    if args.webdav_user.is_none() || args.webdav_password.is_none() {
+        if let Some(ref env_path) = args.env_file {
+            dotenv::from_path(Path::new(env_path))
+                .map_err(|_| format!("Could not read env file: {}", env_path))?;
+        } else {
+            dotenv().ok();
+        }
+    }
+
+    let user = args
+        .webdav_user
+        .clone()
+        .or_else(|| std::env::var("WEBDAV_USER").ok())
+        .ok_or_else(|| "Could not find WebDAV username (--webdav-user or WEBDAV_USER)".to_string())?;
+
+    let password = args
+        .webdav_password
+        .clone()
+        .or_else(|| std::env::var("WEBDAV_PASSWORD").ok())
+        .ok_or_else(|| {
+            "Could not find WebDAV password (--webdav-password or WEBDAV_PASSWORD)".to_string()
+        })?;
+
+    Ok(Some(WebdavTarget { url, user, password }))
+}
+
+/// Parses a `--date` argument into the set of dates it refers to.
+///
+/// Accepts a comma-separated list whose items are each either a single date
+/// (`YYYY-MM-DD`, `today`, `tomorrow`), a range (`2024-01-08..2024-01-12`), or
+/// the keyword `week` for the current Monday-Friday span.
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<NaiveDate>)`, sorted and deduplicated, if parsing is
+/// successful, otherwise returns `Err(String)`.
+fn parse_dates(date_str: &str) -> Result<Vec<NaiveDate>, String> {
+    let mut dates = Vec::new();
+    for part in date_str.split(',') {
+        dates.extend(parse_date_part(part.trim())?);
+    }
+
+    dates.sort();
+    dates.dedup();
+    Ok(dates)
+}
+
+/// Largest number of days a single `start..end` range may span, to avoid
+/// building a multi-year `Vec<NaiveDate>` and hammering the OpenMensa API
+/// with one request per date.
+const MAX_RANGE_DAYS: i64 = 31;
+
+/// Parses a single comma-separated item of a `--date` argument.
+fn parse_date_part(part: &str) -> Result<Vec<NaiveDate>, String> {
+    if let Some((start, end)) = part.split_once("..") {
+        let start = parse_single_date(start.trim())?;
+        let end = parse_single_date(end.trim())?;
+        if start > end {
+            return Err(format!("Invalid date range: {} is after {}", start, end));
+        }
+
+        let span_days = (end - start).num_days() + 1;
+        if span_days > MAX_RANGE_DAYS {
+            return Err(format!(
+                "Date range {}..{} spans {} days, which is more than the {}-day limit",
+                start, end, span_days, MAX_RANGE_DAYS
+            ));
+        }
+
+        let mut dates = Vec::new();
+        let mut current = start;
+        while current <= end {
+            dates.push(current);
+            current += chrono::Duration::days(1);
+        }
+        return Ok(dates);
+    }
+
+    if part == "week" {
+        let today = Utc::now().date_naive();
+        let monday = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+        return Ok((0..5).map(|offset| monday + chrono::Duration::days(offset)).collect());
+    }
+
+    Ok(vec![parse_single_date(part)?])
+}
+
+/// Parses a single date keyword or `YYYY-MM-DD` string into a `NaiveDate`.
+fn parse_single_date(date_str: &str) -> Result<NaiveDate, String> {
+    match date_str {
+        "today" => Ok(Utc::now().date_naive()),
+        "tomorrow" => Ok(Utc::now().date_naive() + chrono::Duration::days(1)),
+        _ => NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .map_err(|err| format!("Invalid date format: {}", err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parses_a_single_date() {
+        assert_eq!(parse_dates("2024-01-08").unwrap(), vec![date(2024, 1, 8)]);
+    }
+
+    #[test]
+    fn parses_a_range() {
+        assert_eq!(
+            parse_dates("2024-01-08..2024-01-12").unwrap(),
+            vec![
+                date(2024, 1, 8),
+                date(2024, 1, 9),
+                date(2024, 1, 10),
+                date(2024, 1, 11),
+                date(2024, 1, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_a_reversed_range() {
+        assert!(parse_dates("2024-01-12..2024-01-08").is_err());
+    }
+
+    #[test]
+    fn rejects_a_range_past_the_max_span() {
+        assert!(parse_dates("2024-01-01..9124-01-01").is_err());
+    }
+
+    #[test]
+    fn accepts_a_range_at_the_max_span() {
+        assert!(parse_dates("2024-01-01..2024-01-31").is_ok());
+    }
+
+    #[test]
+    fn week_lands_on_monday_through_friday() {
+        let dates = parse_dates("week").unwrap();
+        assert_eq!(dates.len(), 5);
+        assert_eq!(dates[0].weekday(), chrono::Weekday::Mon);
+        assert_eq!(dates[4].weekday(), chrono::Weekday::Fri);
+        for pair in dates.windows(2) {
+            assert_eq!(pair[1] - pair[0], chrono::Duration::days(1));
+        }
+    }
+
+    #[test]
+    fn comma_list_is_sorted_and_deduped() {
+        assert_eq!(
+            parse_dates("2024-01-12,2024-01-08,2024-01-08").unwrap(),
+            vec![date(2024, 1, 8), date(2024, 1, 12)]
+        );
+    }
+
+    #[test]
+    fn overlapping_range_and_single_date_dedup() {
+        assert_eq!(
+            parse_dates("2024-01-08..2024-01-09,2024-01-09").unwrap(),
+            vec![date(2024, 1, 8), date(2024, 1, 9)]
+        );
+    }
+
+    #[test]
+    fn tomorrow_is_today_plus_one_day() {
+        let dates = parse_dates("today,tomorrow").unwrap();
+        assert_eq!(dates, vec![dates[0], dates[0] + chrono::Duration::days(1)]);
+    }
+
+    #[test]
+    fn rejects_an_invalid_date() {
+        assert!(parse_dates("not-a-date").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+use crate::cli;
+
+/// Runs the `meme` subcommand.
+pub async fn run() {
+    cli::meme().await;
+}
@@ -0,0 +1,5 @@
+pub mod bot;
+pub mod cache;
+pub mod fact;
+pub mod meals;
+pub mod meme;
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/// Parsed contents of `config.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Configs {
+    pub locations: Locations,
+
+    #[serde(default)]
+    pub filters: Filters,
+
+    #[serde(default)]
+    pub calendar: Calendar,
+}
+
+/// Default canteens to query when no `--id`/`--location` is given.
+#[derive(Debug, Deserialize)]
+pub struct Locations {
+    pub canteens: Vec<u32>,
+}
+
+/// Default meal filters, overridden by the matching `--vegetarian`/`--vegan`/
+/// `--category`/`--max-price` flags when those are passed.
+#[derive(Debug, Deserialize, Default)]
+pub struct Filters {
+    #[serde(default)]
+    pub vegetarian: bool,
+
+    #[serde(default)]
+    pub vegan: bool,
+
+    pub category: Option<String>,
+
+    pub max_price: Option<f64>,
+}
+
+/// Default lunch window used for `--ical` events, overridden by the matching
+/// `[calendar]` keys when present.
+#[derive(Debug, Deserialize, Default)]
+pub struct Calendar {
+    /// Start of the lunch window, as `HH:MM` (default `11:30`).
+    pub lunch_start: Option<String>,
+
+    /// End of the lunch window, as `HH:MM` (default `14:00`).
+    pub lunch_end: Option<String>,
+}
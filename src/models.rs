@@ -0,0 +1,237 @@
+use openmensa_rust_interface::Meal;
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+
+/// A dietary marker derived from a meal's OpenMensa notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DietaryFlag {
+    Vegetarian,
+    Vegan,
+    Pork,
+}
+
+impl DietaryFlag {
+    fn icon(self) -> &'static str {
+        match self {
+            DietaryFlag::Vegetarian => "🥕",
+            DietaryFlag::Vegan => "🌱",
+            DietaryFlag::Pork => "🐖",
+        }
+    }
+}
+
+/// A meal flattened into the columns shown by `render_table`.
+#[derive(Debug, Clone, Serialize, Deserialize, Tabled)]
+pub struct TabledMeal {
+    #[tabled(rename = "Category")]
+    pub category: String,
+
+    #[tabled(rename = "Meal")]
+    pub name: String,
+
+    #[tabled(rename = "Diet")]
+    pub icons: String,
+
+    #[tabled(rename = "Price (Student)")]
+    pub price_student: String,
+
+    #[tabled(rename = "Price (Employee)")]
+    pub price_employee: String,
+
+    #[tabled(skip)]
+    pub flags: Vec<DietaryFlag>,
+
+    #[tabled(skip)]
+    pub price_student_value: Option<f64>,
+
+    #[tabled(skip)]
+    pub price_employee_value: Option<f64>,
+}
+
+impl TabledMeal {
+    /// Whether this meal is vegetarian (vegan meals count as vegetarian too).
+    pub fn is_vegetarian(&self) -> bool {
+        self.flags.contains(&DietaryFlag::Vegetarian) || self.flags.contains(&DietaryFlag::Vegan)
+    }
+
+    /// Whether this meal is vegan.
+    pub fn is_vegan(&self) -> bool {
+        self.flags.contains(&DietaryFlag::Vegan)
+    }
+}
+
+impl From<Meal> for TabledMeal {
+    fn from(meal: Meal) -> Self {
+        let flags = parse_dietary_flags(&meal.notes);
+        let icons = flags.iter().map(|flag| flag.icon()).collect::<String>();
+
+        TabledMeal {
+            category: meal.category,
+            name: meal.name,
+            icons,
+            price_student: format_price(meal.prices.students),
+            price_employee: format_price(meal.prices.employees),
+            flags,
+            price_student_value: meal.prices.students,
+            price_employee_value: meal.prices.employees,
+        }
+    }
+}
+
+/// Normalizes a meal's free-text OpenMensa notes into dietary flags.
+fn parse_dietary_flags(notes: &[String]) -> Vec<DietaryFlag> {
+    let joined = notes.join(" ").to_lowercase();
+    let mut flags = Vec::new();
+
+    if joined.contains("vegan") {
+        flags.push(DietaryFlag::Vegan);
+    } else if joined.contains("vegetarisch") || joined.contains("vegetarian") {
+        flags.push(DietaryFlag::Vegetarian);
+    }
+
+    if joined.contains("schwein") || joined.contains("pork") {
+        flags.push(DietaryFlag::Pork);
+    }
+
+    flags
+}
+
+fn format_price(price: Option<f64>) -> String {
+    match price {
+        Some(price) => format!("{:.2} €", price),
+        None => "-".to_string(),
+    }
+}
+
+/// Dietary and price filters applied to meal listings before formatting.
+#[derive(Debug, Clone, Default)]
+pub struct MealFilter {
+    pub vegetarian: bool,
+    pub vegan: bool,
+    pub category: Option<String>,
+    pub max_price: Option<f64>,
+}
+
+impl MealFilter {
+    /// Whether `meal` should be kept under this filter.
+    pub fn matches(&self, meal: &TabledMeal) -> bool {
+        if self.vegan && !meal.is_vegan() {
+            return false;
+        }
+
+        if self.vegetarian && !meal.is_vegetarian() {
+            return false;
+        }
+
+        if let Some(ref category) = self.category {
+            if !meal.category.eq_ignore_ascii_case(category) {
+                return false;
+            }
+        }
+
+        if let Some(max_price) = self.max_price {
+            if meal.price_student_value.map_or(false, |price| price > max_price) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meal(notes: &[&str], category: &str, price_student: Option<f64>) -> TabledMeal {
+        let notes: Vec<String> = notes.iter().map(|note| note.to_string()).collect();
+        let flags = parse_dietary_flags(&notes);
+        let icons = flags.iter().map(|flag| flag.icon()).collect::<String>();
+
+        TabledMeal {
+            category: category.to_string(),
+            name: "Test Meal".to_string(),
+            icons,
+            price_student: format_price(price_student),
+            price_employee: format_price(price_student),
+            flags,
+            price_student_value: price_student,
+            price_employee_value: price_student,
+        }
+    }
+
+    #[test]
+    fn parses_vegan_notes() {
+        let flags = parse_dietary_flags(&["Vegan".to_string()]);
+        assert_eq!(flags, vec![DietaryFlag::Vegan]);
+    }
+
+    #[test]
+    fn parses_vegetarian_notes_in_german() {
+        let flags = parse_dietary_flags(&["Vegetarisch".to_string()]);
+        assert_eq!(flags, vec![DietaryFlag::Vegetarian]);
+    }
+
+    #[test]
+    fn vegan_takes_priority_over_vegetarian_note() {
+        let flags = parse_dietary_flags(&["Vegan, Vegetarisch".to_string()]);
+        assert_eq!(flags, vec![DietaryFlag::Vegan]);
+    }
+
+    #[test]
+    fn parses_pork_notes() {
+        let flags = parse_dietary_flags(&["Schwein".to_string()]);
+        assert_eq!(flags, vec![DietaryFlag::Pork]);
+    }
+
+    #[test]
+    fn filter_vegan_drops_vegetarian_only_meals() {
+        let filter = MealFilter {
+            vegan: true,
+            ..Default::default()
+        };
+        assert!(!filter.matches(&meal(&["Vegetarisch"], "Main", None)));
+        assert!(filter.matches(&meal(&["Vegan"], "Main", None)));
+    }
+
+    #[test]
+    fn filter_vegetarian_accepts_vegan_meals_too() {
+        let filter = MealFilter {
+            vegetarian: true,
+            ..Default::default()
+        };
+        assert!(filter.matches(&meal(&["Vegan"], "Main", None)));
+    }
+
+    #[test]
+    fn filter_category_is_case_insensitive() {
+        let filter = MealFilter {
+            category: Some("main".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&meal(&[], "Main", None)));
+        assert!(!filter.matches(&meal(&[], "Dessert", None)));
+    }
+
+    #[test]
+    fn filter_max_price_keeps_meals_without_a_known_price() {
+        let filter = MealFilter {
+            max_price: Some(3.0),
+            ..Default::default()
+        };
+        assert!(filter.matches(&meal(&[], "Main", Some(2.5))));
+        assert!(!filter.matches(&meal(&[], "Main", Some(3.5))));
+        assert!(filter.matches(&meal(&[], "Main", None)));
+    }
+
+    #[test]
+    fn tabled_meal_round_trips_through_json_with_flags_intact() {
+        let original = meal(&["Vegan"], "Main", Some(4.2));
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: TabledMeal = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.flags, original.flags);
+        assert_eq!(restored.price_student_value, original.price_student_value);
+        assert_eq!(restored.price_employee_value, original.price_employee_value);
+    }
+}